@@ -0,0 +1,99 @@
+//! Builds the trait/implementation cross-reference graph: for every
+//! trait, which types implement it, and for every type, which traits
+//! (and which inherent `impl` blocks) it has.
+
+use std::collections::HashMap;
+
+use crate::model::{Path, Symbol, SymbolKind};
+
+/// One `impl Trait for Type` block resolved against the trait's
+/// definition, recording which of the trait's methods this impl actually
+/// defines versus inherits from a default body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraitImpl {
+    pub self_type: Path,
+    /// Whether `trait_path` was resolved to a known trait definition in
+    /// this symbol set. `false` for traits defined elsewhere (another
+    /// crate, or a file that wasn't parsed).
+    pub resolved: bool,
+    /// Required or default trait methods this impl defines itself.
+    pub overridden: Vec<String>,
+    /// Trait methods with a default body that this impl leaves untouched.
+    /// Only meaningful when `resolved` is `true`.
+    pub inherited: Vec<String>,
+}
+
+/// The resolved trait/impl graph for a set of symbols.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TraitGraph {
+    /// Trait name -> its implementors.
+    pub implementors: HashMap<String, Vec<TraitImpl>>,
+    /// Type name -> the traits it implements.
+    pub implements: HashMap<String, Vec<String>>,
+    /// Type name -> its inherent `impl` blocks (self type display name,
+    /// e.g. `Point<f32>`), kept separate from trait impls.
+    pub inherent_impls: HashMap<String, Vec<String>>,
+}
+
+/// Strips generic arguments and module qualification from a path,
+/// leaving just the base name used to resolve `impl Trait for _` against
+/// a known `trait` definition, e.g. `my_mod::Display<'a>` -> `Display`.
+fn base_name(path: &str) -> &str {
+    let without_generics = path.split('<').next().unwrap_or(path);
+    without_generics.rsplit("::").next().unwrap_or(without_generics).trim()
+}
+
+/// Builds the trait/impl cross-reference graph from a set of extracted
+/// symbols. Traits must be present in `symbols` to resolve against; an
+/// `impl Trait for Type` whose trait isn't found among `symbols` is still
+/// recorded, just marked unresolved.
+pub fn build_trait_graph(symbols: &[Symbol]) -> TraitGraph {
+    let traits: HashMap<&str, &Symbol> = symbols
+        .iter()
+        .filter(|s| s.kind == SymbolKind::Trait)
+        .map(|s| (base_name(&s.name), s))
+        .collect();
+
+    let mut graph = TraitGraph::default();
+
+    for sym in symbols.iter().filter(|s| s.kind == SymbolKind::Impl) {
+        let self_key = base_name(&sym.name).to_string();
+
+        match &sym.trait_path {
+            None => {
+                graph
+                    .inherent_impls
+                    .entry(self_key)
+                    .or_default()
+                    .push(sym.name.clone());
+            }
+            Some(trait_path) => {
+                let trait_key = base_name(trait_path).to_string();
+                let defined: Vec<String> = sym.methods.iter().map(|m| m.name.clone()).collect();
+
+                let (resolved, inherited) = match traits.get(trait_key.as_str()) {
+                    Some(trait_sym) => {
+                        let inherited = trait_sym
+                            .methods
+                            .iter()
+                            .filter(|m| m.has_body && !defined.contains(&m.name))
+                            .map(|m| m.name.clone())
+                            .collect();
+                        (true, inherited)
+                    }
+                    None => (false, Vec::new()),
+                };
+
+                graph.implements.entry(self_key.clone()).or_default().push(trait_key.clone());
+                graph.implementors.entry(trait_key).or_default().push(TraitImpl {
+                    self_type: sym.name.clone(),
+                    resolved,
+                    overridden: defined,
+                    inherited,
+                });
+            }
+        }
+    }
+
+    graph
+}
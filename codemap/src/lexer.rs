@@ -0,0 +1,132 @@
+//! A minimal tokenizer for the subset of Rust syntax that the extractor
+//! needs to recognize item boundaries. It does not attempt to tokenize
+//! full Rust (no macro-by-example, no raw strings edge cases); it only
+//! needs to be good enough to find item headers and matching braces.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Ident,
+    Punct,
+    Lifetime,
+    StringLit,
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+}
+
+/// Strips comments, then splits the remaining source into a flat token
+/// stream, keeping each string literal whole as one opaque token.
+pub fn tokenize(src: &str) -> Vec<Token> {
+    let chars: Vec<char> = src.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let n = chars.len();
+
+    while i < n {
+        let c = chars[i];
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        // Line comments.
+        if c == '/' && i + 1 < n && chars[i + 1] == '/' {
+            while i < n && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+
+        // Block comments (non-nested, sufficient for our purposes).
+        if c == '/' && i + 1 < n && chars[i + 1] == '*' {
+            i += 2;
+            while i + 1 < n && !(chars[i] == '*' && chars[i + 1] == '/') {
+                i += 1;
+            }
+            i += 2;
+            continue;
+        }
+
+        // String literals (kept as a single opaque token).
+        if c == '"' {
+            let start = i;
+            i += 1;
+            while i < n && chars[i] != '"' {
+                if chars[i] == '\\' && i + 1 < n {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+            tokens.push(Token {
+                kind: TokenKind::StringLit,
+                text: chars[start..i.min(n)].iter().collect(),
+            });
+            continue;
+        }
+
+        // Lifetimes and the `'` char-literal punctuation: `'a`, `'static`.
+        if c == '\'' {
+            let start = i;
+            i += 1;
+            if i < n && (chars[i].is_alphabetic() || chars[i] == '_') {
+                while i < n && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Lifetime,
+                    text: chars[start..i].iter().collect(),
+                });
+                continue;
+            }
+            tokens.push(Token {
+                kind: TokenKind::Punct,
+                text: "'".to_string(),
+            });
+            continue;
+        }
+
+        // Identifiers and keywords.
+        if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < n && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token {
+                kind: TokenKind::Ident,
+                text: chars[start..i].iter().collect(),
+            });
+            continue;
+        }
+
+        // Multi-char punctuation that the parser cares about.
+        let two: Option<String> = if i + 1 < n {
+            Some([c, chars[i + 1]].iter().collect())
+        } else {
+            None
+        };
+        if let Some(pair) = two {
+            if ["::", "->", "=>", "&&"].contains(&pair.as_str()) {
+                tokens.push(Token {
+                    kind: TokenKind::Punct,
+                    text: pair,
+                });
+                i += 2;
+                continue;
+            }
+        }
+
+        tokens.push(Token {
+            kind: TokenKind::Punct,
+            text: c.to_string(),
+        });
+        i += 1;
+    }
+
+    tokens
+}
@@ -0,0 +1,13 @@
+//! codemap extracts a navigable symbol map from Rust source files.
+
+pub mod graph;
+pub mod lexer;
+pub mod model;
+pub mod output;
+pub mod parser;
+pub mod query;
+
+pub use graph::{build_trait_graph, TraitGraph, TraitImpl};
+pub use model::{GenericParams, MethodSig, Module, Symbol, SymbolKind, TypeParam, Visibility};
+pub use parser::{parse_module, parse_source};
+pub use query::{evaluate, parse_query, Predicate, QueryNode};
@@ -0,0 +1,65 @@
+use std::env;
+use std::fs;
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: codemap [outline|trait-impls|module-tree] <file.rs>\n   or: codemap query <query> <file.rs>";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let (mode, query, path) = match args.as_slice() {
+        [path] => ("outline", None, path.as_str()),
+        [mode, path] if mode != "query" => (mode.as_str(), None, path.as_str()),
+        [_, query, path] => ("query", Some(query.as_str()), path.as_str()),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let src = match fs::read_to_string(path) {
+        Ok(src) => src,
+        Err(err) => {
+            eprintln!("codemap: failed to read {path}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match mode {
+        "outline" => {
+            let symbols = codemap::parse_source(&src);
+            println!("{}", codemap::output::render_outline(&symbols));
+        }
+        "trait-impls" => {
+            let symbols = codemap::parse_source(&src);
+            let graph = codemap::build_trait_graph(&symbols);
+            println!("{}", codemap::output::render_trait_graph(&graph));
+        }
+        "module-tree" => {
+            let module = codemap::parse_module(&src);
+            println!("{}", codemap::output::render_module_tree(&module));
+        }
+        "query" => {
+            let Some(query) = query else {
+                eprintln!("{USAGE}");
+                return ExitCode::FAILURE;
+            };
+            let node = match codemap::parse_query(query) {
+                Ok(node) => node,
+                Err(err) => {
+                    eprintln!("codemap: invalid query: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let symbols = codemap::parse_source(&src);
+            let graph = codemap::build_trait_graph(&symbols);
+            let matches = codemap::evaluate(&node, &symbols, &graph);
+            println!("{}", codemap::output::render_outline(matches));
+        }
+        other => {
+            eprintln!("codemap: unknown output mode `{other}`");
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,189 @@
+//! The symbol data model shared by the parser and the output renderers.
+
+/// A single type or lifetime parameter, e.g. the `T: Clone` in `struct Foo<T: Clone>`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TypeParam {
+    pub name: String,
+    pub bounds: Vec<String>,
+}
+
+/// The generic signature of an item: its lifetime parameters, type
+/// parameters (with bounds merged in from any `where` clause), and any
+/// leftover `where` predicates that don't attach to a single named
+/// parameter (e.g. `Self: Sized`, `'a: 'b`).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GenericParams {
+    pub lifetimes: Vec<String>,
+    pub type_params: Vec<TypeParam>,
+    pub extra_where: Vec<String>,
+}
+
+impl GenericParams {
+    pub fn is_empty(&self) -> bool {
+        self.lifetimes.is_empty() && self.type_params.is_empty() && self.extra_where.is_empty()
+    }
+
+    /// Renders the generic parameter list the way it should appear after a
+    /// symbol's base name, e.g. `<T: Clone + 'a>`.
+    pub fn render(&self) -> String {
+        if self.lifetimes.is_empty() && self.type_params.is_empty() {
+            return String::new();
+        }
+        let mut parts: Vec<String> = self.lifetimes.iter().map(|l| l.to_string()).collect();
+        for tp in &self.type_params {
+            if tp.bounds.is_empty() {
+                parts.push(tp.name.clone());
+            } else {
+                parts.push(format!("{}: {}", tp.name, tp.bounds.join(" + ")));
+            }
+        }
+        format!("<{}>", parts.join(", "))
+    }
+}
+
+/// A path to an item, e.g. `UserService` or `std::fmt::Display`. Kept as a
+/// plain string rather than a segmented structure since nothing here needs
+/// to do more than compare and render paths.
+pub type Path = String;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SymbolKind {
+    Struct,
+    Enum,
+    Trait,
+    Impl,
+    Function,
+}
+
+/// A method declared inside a `trait` or `impl` block.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MethodSig {
+    pub name: String,
+    /// `true` if the method has a body (a trait default, or any impl
+    /// method); `false` for a trait method declaration ending in `;`.
+    pub has_body: bool,
+}
+
+/// An item's resolved visibility, as declared on the item itself (not yet
+/// accounting for whether its enclosing modules are also visible — see
+/// [`Symbol::externally_visible`] for that).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Visibility {
+    #[default]
+    Private,
+    Public,
+    PubCrate,
+    /// `pub(in some::path)`, `pub(super)`, or `pub(self)` — restricted to
+    /// the named scope, which is kept as written rather than resolved.
+    PubIn(Path),
+}
+
+impl Visibility {
+    /// Renders the visibility as a source-style marker: `pub`,
+    /// `pub(crate)`, `pub(in ..)` for any restricted scope (the scope's
+    /// path is not included), or `""` for private.
+    pub fn render(&self) -> &str {
+        match self {
+            Visibility::Private => "",
+            Visibility::Public => "pub",
+            Visibility::PubCrate => "pub(crate)",
+            Visibility::PubIn(_) => "pub(in ..)",
+        }
+    }
+
+    fn is_externally_public(&self) -> bool {
+        matches!(self, Visibility::Public)
+    }
+}
+
+/// A single extracted item.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symbol {
+    pub kind: SymbolKind,
+    /// The symbol's fully-qualified name, prefixed with its enclosing
+    /// module path (e.g. `utils::format_number`). Bare for crate-root items.
+    pub name: String,
+    pub generics: GenericParams,
+    /// Methods declared directly inside the item's body. Populated for
+    /// `Trait` and `Impl` symbols; empty otherwise.
+    pub methods: Vec<MethodSig>,
+    /// For `Impl` symbols, the trait being implemented (`impl Trait for
+    /// Self`), or `None` for an inherent impl. Unused for other kinds.
+    pub trait_path: Option<Path>,
+    /// The visibility declared on the item itself.
+    pub visibility: Visibility,
+    /// Whether this item is reachable from outside the crate: it must be
+    /// `pub` *and* every enclosing module on its path must also be `pub`.
+    pub externally_visible: bool,
+    /// Named field declarations, in order. Populated for `Struct` symbols
+    /// with named fields (`struct Foo { bar: T }`); empty for tuple/unit
+    /// structs, and for every other kind.
+    pub fields: Vec<String>,
+    /// Whether a `Function` symbol is declared `async fn`. Unused for
+    /// other kinds.
+    pub is_async: bool,
+    /// Whether a `Function` symbol is declared `unsafe fn`. Unused for
+    /// other kinds.
+    pub is_unsafe: bool,
+    /// Whether a `Function` symbol is declared `const fn`. Unused for
+    /// other kinds.
+    pub is_const: bool,
+}
+
+impl Symbol {
+    /// The name as it should be displayed, with its generic signature
+    /// applied, e.g. `Point<T: Clone + 'a>`.
+    ///
+    /// `Impl` symbols are the exception: their `name` is the self type's
+    /// own path, already carrying whatever type arguments it was
+    /// implemented for (e.g. `Point<f32>` or `Point<T: Clone>`), so it is
+    /// rendered as-is rather than appending `generics` a second time.
+    pub fn display_name(&self) -> String {
+        match self.kind {
+            SymbolKind::Impl => self.name.clone(),
+            _ => format!("{}{}", self.name, self.generics.render()),
+        }
+    }
+}
+
+/// A node in the module tree: the items declared directly inside one
+/// `mod` block (or the crate root), plus its nested modules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Module {
+    /// Empty for the crate root.
+    pub name: String,
+    /// Fully-qualified path, e.g. `utils`. Empty for the crate root.
+    pub path: Path,
+    pub visibility: Visibility,
+    pub symbols: Vec<Symbol>,
+    pub modules: Vec<Module>,
+}
+
+impl Module {
+    /// Flattens the tree depth-first: a module's own items in declaration
+    /// order, then the items of each child module.
+    pub fn flatten(&self) -> Vec<Symbol> {
+        let mut out = self.symbols.clone();
+        for child in &self.modules {
+            out.extend(child.flatten());
+        }
+        out
+    }
+
+    /// Marks `externally_visible` on every symbol in the tree:
+    /// an item is only reachable from outside the crate if it (and every
+    /// enclosing module) is `pub`. `parent_reachable` is whether the path
+    /// down to this module's parent is fully public; pass `true` for the
+    /// crate root itself.
+    pub fn resolve_external_visibility(&mut self, parent_reachable: bool) {
+        // The crate root has no visibility of its own to check.
+        let self_reachable =
+            parent_reachable && (self.path.is_empty() || self.visibility.is_externally_public());
+        for symbol in &mut self.symbols {
+            symbol.externally_visible = self_reachable && symbol.visibility.is_externally_public();
+        }
+        for child in &mut self.modules {
+            child.resolve_external_visibility(self_reachable);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Renders extracted symbols back out as readable text.
+
+use crate::graph::TraitGraph;
+use crate::model::{Module, Symbol, SymbolKind};
+
+fn kind_label(kind: &SymbolKind) -> &'static str {
+    match kind {
+        SymbolKind::Struct => "struct",
+        SymbolKind::Enum => "enum",
+        SymbolKind::Trait => "trait",
+        SymbolKind::Impl => "impl",
+        SymbolKind::Function => "fn",
+    }
+}
+
+/// Renders one line per symbol: `<modifiers> <kind> <name><generics>`,
+/// e.g. `async fn process_data` or `const unsafe fn raw_init`.
+pub fn render_outline<'a>(symbols: impl IntoIterator<Item = &'a Symbol>) -> String {
+    symbols
+        .into_iter()
+        .map(|s| format!("{}{} {}", modifiers(s), kind_label(&s.kind), s.display_name()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders a function's `const`/`async`/`unsafe` modifiers in the order
+/// Rust itself requires them, followed by a trailing space if any are
+/// present.
+fn modifiers(symbol: &Symbol) -> String {
+    let mut parts = Vec::new();
+    if symbol.is_const {
+        parts.push("const");
+    }
+    if symbol.is_async {
+        parts.push("async");
+    }
+    if symbol.is_unsafe {
+        parts.push("unsafe");
+    }
+    if parts.is_empty() {
+        String::new()
+    } else {
+        format!("{} ", parts.join(" "))
+    }
+}
+
+/// Renders the trait/impl graph: for each trait, its implementors and
+/// which of the trait's default methods each one leaves inherited.
+pub fn render_trait_graph(graph: &TraitGraph) -> String {
+    let mut trait_names: Vec<&String> = graph.implementors.keys().collect();
+    trait_names.sort();
+
+    let mut lines = Vec::new();
+    for trait_name in trait_names {
+        lines.push(format!("trait {trait_name}"));
+        let implementors = &graph.implementors[trait_name];
+        for imp in implementors {
+            let note = if !imp.resolved {
+                " (trait definition not found; methods not compared)".to_string()
+            } else if imp.inherited.is_empty() {
+                String::new()
+            } else {
+                format!(" (inherits default: {})", imp.inherited.join(", "))
+            };
+            lines.push(format!("  impl for {}{note}", imp.self_type));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Renders the full module tree: each `mod` nested under its parent, with
+/// its items indented beneath it and prefixed with their visibility
+/// (`pub`, `pub(crate)`, `pub(in ..)`, or nothing for private). Items
+/// reachable from outside the crate are marked `[external]`.
+pub fn render_module_tree(root: &Module) -> String {
+    let mut lines = Vec::new();
+    render_module(root, 0, &mut lines);
+    lines.join("\n")
+}
+
+fn render_module(module: &Module, depth: usize, lines: &mut Vec<String>) {
+    let is_root = module.path.is_empty();
+    if !is_root {
+        let vis = module.visibility.render();
+        let header = if vis.is_empty() {
+            format!("mod {}", module.name)
+        } else {
+            format!("{vis} mod {}", module.name)
+        };
+        lines.push(format!("{}{header}", "  ".repeat(depth)));
+    }
+
+    let item_depth = if is_root { depth } else { depth + 1 };
+    let item_indent = "  ".repeat(item_depth);
+    let qualifier = format!("{}::", module.path);
+    for symbol in &module.symbols {
+        let local_name = symbol
+            .display_name()
+            .strip_prefix(&qualifier)
+            .map(str::to_string)
+            .unwrap_or_else(|| symbol.display_name());
+        let vis = symbol.visibility.render();
+        let marker = if vis.is_empty() { String::new() } else { format!("{vis} ") };
+        let external = if symbol.externally_visible { " [external]" } else { "" };
+        lines.push(format!("{item_indent}{marker}{} {local_name}{external}", kind_label(&symbol.kind)));
+    }
+
+    for child in &module.modules {
+        render_module(child, item_depth, lines);
+    }
+}
@@ -0,0 +1,600 @@
+//! Extracts [`Symbol`]s from Rust source by scanning the token stream for
+//! item headers. This is a pragmatic, signature-level parser: it does not
+//! build a full AST, it only needs to recognize where items start, what
+//! their name and generic signature are, and where their body ends.
+
+use crate::lexer::{tokenize, Token, TokenKind};
+use crate::model::{GenericParams, MethodSig, Module, Symbol, SymbolKind, TypeParam, Visibility};
+
+/// Parses a source file into a flat list of symbols (struct, enum, trait,
+/// impl, and function items), including those nested inside `mod` blocks,
+/// with fully-qualified names and resolved visibility.
+pub fn parse_source(src: &str) -> Vec<Symbol> {
+    parse_module(src).flatten()
+}
+
+/// Parses a source file into its full module tree, rooted at the crate
+/// (whose own `name`/`path` are empty).
+pub fn parse_module(src: &str) -> Module {
+    let tokens = tokenize(src);
+    let mut i = 0;
+    let (symbols, modules) = parse_items(&tokens, &mut i, tokens.len(), "");
+    let mut root = Module {
+        name: String::new(),
+        path: String::new(),
+        visibility: Visibility::Public,
+        symbols,
+        modules,
+    };
+    root.resolve_external_visibility(true);
+    root
+}
+
+fn ident_at(tokens: &[Token], i: usize) -> Option<&str> {
+    tokens.get(i).filter(|t| t.kind == TokenKind::Ident).map(|t| t.text.as_str())
+}
+
+fn punct_at(tokens: &[Token], i: usize, text: &str) -> bool {
+    tokens
+        .get(i)
+        .map(|t| t.kind == TokenKind::Punct && t.text == text)
+        .unwrap_or(false)
+}
+
+/// Prefixes `name` with `module_path`, e.g. `qualify("utils", "format_number")
+/// == "utils::format_number"`. Crate-root items (`module_path == ""`) are
+/// left bare.
+fn qualify(module_path: &str, name: &str) -> String {
+    if module_path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{module_path}::{name}")
+    }
+}
+
+/// Parses every item in `tokens[*i..end]` (a file, or the body of a `mod`
+/// block), recursing into nested `mod`s and qualifying names with
+/// `module_path`.
+fn parse_items(tokens: &[Token], i: &mut usize, end: usize, module_path: &str) -> (Vec<Symbol>, Vec<Module>) {
+    let mut symbols = Vec::new();
+    let mut modules = Vec::new();
+
+    while *i < end {
+        let start = *i;
+        let visibility = maybe_parse_visibility(tokens, i);
+
+        let item = if starts_fn_item(tokens, *i) {
+            parse_fn(tokens, i)
+        } else {
+            match ident_at(tokens, *i) {
+                Some("mod") => {
+                    if let Some(module) = parse_mod(tokens, i, module_path, visibility) {
+                        modules.push(module);
+                    }
+                    continue;
+                }
+                Some("struct") => parse_struct_or_enum(tokens, i, SymbolKind::Struct),
+                Some("enum") => parse_struct_or_enum(tokens, i, SymbolKind::Enum),
+                Some("trait") => parse_trait(tokens, i),
+                Some("impl") => parse_impl(tokens, i),
+                Some("fn") => parse_fn(tokens, i),
+                _ => None,
+            }
+        };
+
+        match item {
+            Some(mut sym) => {
+                sym.name = qualify(module_path, &sym.name);
+                sym.visibility = visibility;
+                symbols.push(sym);
+            }
+            None => {
+                // Not an item we recognize (a `use`, a field, an
+                // attribute, ...); if visibility parsing didn't move us
+                // forward either, advance one token to avoid looping.
+                if *i == start {
+                    *i += 1;
+                }
+            }
+        }
+    }
+
+    (symbols, modules)
+}
+
+/// Whether the item starting at `i` is a function, possibly behind
+/// `async`/`unsafe`/`const` modifiers — `const NAME: T = ...` must not be
+/// mistaken for `const fn`, so this only returns `true` when a `fn`
+/// keyword is actually reachable through the modifier sequence.
+fn starts_fn_item(tokens: &[Token], i: usize) -> bool {
+    let mut lookahead = i;
+    while matches!(ident_at(tokens, lookahead), Some("async") | Some("unsafe") | Some("const")) {
+        lookahead += 1;
+    }
+    ident_at(tokens, lookahead) == Some("fn")
+}
+
+/// Parses an optional `pub` / `pub(crate)` / `pub(in path)` prefix.
+fn maybe_parse_visibility(tokens: &[Token], i: &mut usize) -> Visibility {
+    if ident_at(tokens, *i) != Some("pub") {
+        return Visibility::Private;
+    }
+    *i += 1;
+    if !punct_at(tokens, *i, "(") {
+        return Visibility::Public;
+    }
+    let open = *i;
+    skip_balanced(tokens, i, "(", ")");
+    let inner = &tokens[open + 1..*i - 1];
+    if inner.len() == 1 && inner[0].text == "crate" {
+        return Visibility::PubCrate;
+    }
+    Visibility::PubIn(render_tokens(inner))
+}
+
+/// Parses a `mod name { ... }` or `mod name;` declaration. The latter
+/// refers to another file, which this single-file parser can't resolve,
+/// so it's recorded as an empty module.
+fn parse_mod(tokens: &[Token], i: &mut usize, parent_path: &str, visibility: Visibility) -> Option<Module> {
+    *i += 1; // consume `mod`
+    let name = ident_at(tokens, *i)?.to_string();
+    *i += 1;
+    let path = qualify(parent_path, &name);
+
+    if punct_at(tokens, *i, ";") {
+        *i += 1;
+        return Some(Module { name, path, visibility, symbols: Vec::new(), modules: Vec::new() });
+    }
+    if !punct_at(tokens, *i, "{") {
+        return None;
+    }
+
+    let mut probe = *i;
+    skip_balanced(tokens, &mut probe, "{", "}");
+    let close = probe; // index just past the matching `}`
+
+    *i += 1; // step past the opening `{`
+    let (symbols, modules) = parse_items(tokens, i, close - 1, &path);
+    *i = close;
+
+    Some(Module { name, path, visibility, symbols, modules })
+}
+
+fn parse_struct_or_enum(tokens: &[Token], i: &mut usize, kind: SymbolKind) -> Option<Symbol> {
+    *i += 1; // consume `struct`/`enum`
+    let name = ident_at(tokens, *i)?.to_string();
+    *i += 1;
+
+    let mut generics = maybe_parse_generics(tokens, i);
+    maybe_merge_where(tokens, i, &mut generics);
+
+    let fields = if kind == SymbolKind::Struct && punct_at(tokens, *i, "{") {
+        parse_named_fields(tokens, i)
+    } else {
+        skip_item_body(tokens, i);
+        Vec::new()
+    };
+
+    Some(Symbol {
+        kind,
+        name,
+        generics,
+        methods: Vec::new(),
+        trait_path: None,
+        visibility: Visibility::Private,
+        externally_visible: false,
+        fields,
+        is_async: false,
+        is_unsafe: false,
+        is_const: false,
+    })
+}
+
+/// Parses a `{ field: Type, ... }` struct body (`tokens[*i]` must be its
+/// opening `{`) and returns just the field names, in order.
+fn parse_named_fields(tokens: &[Token], i: &mut usize) -> Vec<String> {
+    let open = *i;
+    skip_balanced(tokens, i, "{", "}");
+    let inner = &tokens[open + 1..*i - 1];
+
+    split_top_level(inner, ",").into_iter().filter_map(field_name).collect()
+}
+
+/// Extracts the field name from a single `{ ... }` struct-body segment,
+/// skipping an optional `pub`/`pub(..)` prefix.
+fn field_name(segment: &[Token]) -> Option<String> {
+    let mut idx = 0;
+    if ident_at(segment, idx) == Some("pub") {
+        idx += 1;
+        if segment.get(idx).map(|t| t.text == "(").unwrap_or(false) {
+            skip_balanced(segment, &mut idx, "(", ")");
+        }
+    }
+    let name = ident_at(segment, idx)?.to_string();
+    (segment.get(idx + 1).map(|t| t.text == ":").unwrap_or(false)).then_some(name)
+}
+
+fn parse_trait(tokens: &[Token], i: &mut usize) -> Option<Symbol> {
+    *i += 1; // consume `trait`
+    let name = ident_at(tokens, *i)?.to_string();
+    *i += 1;
+
+    let mut generics = maybe_parse_generics(tokens, i);
+
+    // Skip an optional `: Supertrait + Other` list before the where clause / body.
+    if punct_at(tokens, *i, ":") {
+        *i += 1;
+        while *i < tokens.len() && !punct_at(tokens, *i, "{") && ident_at(tokens, *i) != Some("where") {
+            *i += 1;
+        }
+    }
+
+    maybe_merge_where(tokens, i, &mut generics);
+    let methods = parse_method_sigs_in_block(tokens, i);
+
+    Some(Symbol {
+        kind: SymbolKind::Trait,
+        name,
+        generics,
+        methods,
+        trait_path: None,
+        visibility: Visibility::Private,
+        externally_visible: false,
+        fields: Vec::new(),
+        is_async: false,
+        is_unsafe: false,
+        is_const: false,
+    })
+}
+
+fn parse_impl(tokens: &[Token], i: &mut usize) -> Option<Symbol> {
+    *i += 1; // consume `impl`
+    let mut generics = maybe_parse_generics(tokens, i);
+
+    // What follows is a type path, optionally followed by `for Type`. We
+    // don't yet know which one is the self type, so collect the first
+    // path, then check for `for`.
+    let first_path = collect_type_path(tokens, i, &generics);
+
+    let (trait_path, self_type) = if ident_at(tokens, *i) == Some("for") {
+        *i += 1;
+        (Some(first_path), collect_type_path(tokens, i, &generics))
+    } else {
+        (None, first_path)
+    };
+
+    maybe_merge_where(tokens, i, &mut generics);
+    let methods = parse_method_sigs_in_block(tokens, i);
+
+    Some(Symbol {
+        kind: SymbolKind::Impl,
+        name: self_type,
+        generics,
+        methods,
+        trait_path,
+        visibility: Visibility::Private,
+        externally_visible: false,
+        fields: Vec::new(),
+        is_async: false,
+        is_unsafe: false,
+        is_const: false,
+    })
+}
+
+fn parse_fn(tokens: &[Token], i: &mut usize) -> Option<Symbol> {
+    let (mut is_async, mut is_unsafe, mut is_const) = (false, false, false);
+    while matches!(ident_at(tokens, *i), Some("async") | Some("unsafe") | Some("const")) {
+        match ident_at(tokens, *i) {
+            Some("async") => is_async = true,
+            Some("unsafe") => is_unsafe = true,
+            Some("const") => is_const = true,
+            _ => unreachable!(),
+        }
+        *i += 1;
+    }
+    *i += 1; // consume `fn`
+    let name = ident_at(tokens, *i)?.to_string();
+    *i += 1;
+
+    let mut generics = maybe_parse_generics(tokens, i);
+
+    // Skip the parameter list.
+    if punct_at(tokens, *i, "(") {
+        skip_balanced(tokens, i, "(", ")");
+    }
+
+    // Skip an optional return type, up to `where`, `{`, or `;`.
+    if punct_at(tokens, *i, "->") {
+        *i += 1;
+        while *i < tokens.len()
+            && !punct_at(tokens, *i, "{")
+            && !punct_at(tokens, *i, ";")
+            && ident_at(tokens, *i) != Some("where")
+        {
+            *i += 1;
+        }
+    }
+
+    maybe_merge_where(tokens, i, &mut generics);
+    skip_item_body(tokens, i);
+
+    Some(Symbol {
+        kind: SymbolKind::Function,
+        name,
+        generics,
+        methods: Vec::new(),
+        trait_path: None,
+        visibility: Visibility::Private,
+        externally_visible: false,
+        fields: Vec::new(),
+        is_async,
+        is_unsafe,
+        is_const,
+    })
+}
+
+/// Collects a (possibly generic) type path like `Point<T>` or
+/// `module::Trait<'a, U>` into its rendered display form, e.g. `Point<T>`.
+///
+/// Type arguments that are bare identifiers matching one of the enclosing
+/// `impl`'s own generic parameters are rendered with that parameter's
+/// bounds folded in (e.g. `impl<T: Clone> Point<T>` becomes
+/// `Point<T: Clone>`), so the bounds that actually distinguish one `impl`
+/// block from another survive into the rendered name.
+fn collect_type_path(tokens: &[Token], i: &mut usize, impl_generics: &GenericParams) -> String {
+    let mut out = String::new();
+    while let Some(word) = ident_at(tokens, *i) {
+        out.push_str(word);
+        *i += 1;
+        if punct_at(tokens, *i, "::") {
+            out.push_str("::");
+            *i += 1;
+            continue;
+        }
+        if punct_at(tokens, *i, "<") {
+            let open = *i;
+            skip_balanced(tokens, i, "<", ">");
+            let inner = &tokens[open + 1..*i - 1];
+            let args: Vec<String> = split_top_level(inner, ",")
+                .into_iter()
+                .map(|segment| render_type_arg(segment, impl_generics))
+                .collect();
+            out.push('<');
+            out.push_str(&args.join(", "));
+            out.push('>');
+        }
+        break;
+    }
+    out
+}
+
+/// Renders a single type argument, folding in bounds from a matching
+/// impl-level generic parameter when the argument is a bare name.
+fn render_type_arg(segment: &[Token], impl_generics: &GenericParams) -> String {
+    if let [tok] = segment {
+        if let Some(tp) = impl_generics.type_params.iter().find(|tp| tp.name == tok.text) {
+            if !tp.bounds.is_empty() {
+                return format!("{}: {}", tp.name, tp.bounds.join(" + "));
+            }
+        }
+    }
+    render_tokens(segment)
+}
+
+/// Parses a leading `<...>` generic parameter list, if present.
+fn maybe_parse_generics(tokens: &[Token], i: &mut usize) -> GenericParams {
+    let mut generics = GenericParams::default();
+    if !punct_at(tokens, *i, "<") {
+        return generics;
+    }
+    let open = *i;
+    skip_balanced(tokens, i, "<", ">");
+    let inner = &tokens[open + 1..*i - 1];
+
+    for segment in split_top_level(inner, ",") {
+        if segment.is_empty() {
+            continue;
+        }
+        if segment[0].kind == TokenKind::Lifetime {
+            generics.lifetimes.push(segment[0].text.clone());
+            continue;
+        }
+        if ident_at(segment, 0) == Some("const") {
+            generics.type_params.push(TypeParam {
+                name: render_tokens(segment),
+                bounds: Vec::new(),
+            });
+            continue;
+        }
+        if let Some(name) = ident_at(segment, 0) {
+            let mut bounds = Vec::new();
+            if segment.len() > 1 && segment[1].kind == TokenKind::Punct && segment[1].text == ":" {
+                let rest = &segment[2..];
+                let bound_end = rest
+                    .iter()
+                    .position(|t| t.kind == TokenKind::Punct && t.text == "=")
+                    .unwrap_or(rest.len());
+                for bound in split_top_level(&rest[..bound_end], "+") {
+                    if !bound.is_empty() {
+                        bounds.push(render_tokens(bound));
+                    }
+                }
+            }
+            generics.type_params.push(TypeParam { name: name.to_string(), bounds });
+        }
+    }
+
+    generics
+}
+
+/// Parses an optional `where ...` clause (up to `{` or `;`) and merges its
+/// predicates into `generics`.
+fn maybe_merge_where(tokens: &[Token], i: &mut usize, generics: &mut GenericParams) {
+    if ident_at(tokens, *i) != Some("where") {
+        return;
+    }
+    let start = *i + 1;
+    let mut end = start;
+    while end < tokens.len() && !punct_at(tokens, end, "{") && !punct_at(tokens, end, ";") {
+        end += 1;
+    }
+    let clause = &tokens[start..end];
+    *i = end;
+
+    for predicate in split_top_level(clause, ",") {
+        let Some(colon) = predicate.iter().position(|t| t.kind == TokenKind::Punct && t.text == ":") else {
+            continue;
+        };
+        let lhs = render_tokens(&predicate[..colon]);
+        let bounds: Vec<String> = split_top_level(&predicate[colon + 1..], "+")
+            .into_iter()
+            .filter(|b| !b.is_empty())
+            .map(render_tokens)
+            .collect();
+
+        if let Some(tp) = generics.type_params.iter_mut().find(|tp| tp.name == lhs) {
+            for b in bounds {
+                if !tp.bounds.contains(&b) {
+                    tp.bounds.push(b);
+                }
+            }
+        } else {
+            generics.extra_where.push(format!("{}: {}", lhs, bounds.join(" + ")));
+        }
+    }
+}
+
+/// Splits a token slice on a top-level separator (ignoring separators
+/// nested inside `<>`, `()`, or `[]`).
+fn split_top_level<'a>(tokens: &'a [Token], sep: &str) -> Vec<&'a [Token]> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (idx, tok) in tokens.iter().enumerate() {
+        if tok.kind == TokenKind::Punct {
+            match tok.text.as_str() {
+                "<" | "(" | "[" => depth += 1,
+                ">" | ")" | "]" => depth -= 1,
+                t if depth == 0 && t == sep => {
+                    parts.push(&tokens[start..idx]);
+                    start = idx + 1;
+                }
+                _ => {}
+            }
+        }
+    }
+    parts.push(&tokens[start..]);
+    parts
+}
+
+/// Advances `*i` past a balanced `open`/`close` region, assuming
+/// `tokens[*i]` is the opening token.
+fn skip_balanced(tokens: &[Token], i: &mut usize, open: &str, close: &str) {
+    let mut depth = 0i32;
+    loop {
+        match tokens.get(*i) {
+            Some(t) if t.kind == TokenKind::Punct && t.text == open => {
+                depth += 1;
+                *i += 1;
+            }
+            Some(t) if t.kind == TokenKind::Punct && t.text == close => {
+                depth -= 1;
+                *i += 1;
+                if depth == 0 {
+                    return;
+                }
+            }
+            Some(_) => *i += 1,
+            None => return,
+        }
+    }
+}
+
+/// Skips an item's body: either a `{ ... }` block or a bare `;` (for
+/// trait method declarations and unit structs).
+fn skip_item_body(tokens: &[Token], i: &mut usize) {
+    if punct_at(tokens, *i, "{") {
+        skip_balanced(tokens, i, "{", "}");
+    } else if punct_at(tokens, *i, ";") {
+        *i += 1;
+    }
+}
+
+/// Parses a `trait`/`impl` body (`tokens[*i]` must be its opening `{`),
+/// collecting the signature of every `fn` declared directly inside it.
+/// Non-`fn` members (associated consts/types, attributes, doc comments)
+/// are skipped over without inspection.
+fn parse_method_sigs_in_block(tokens: &[Token], i: &mut usize) -> Vec<MethodSig> {
+    let mut methods = Vec::new();
+    if !punct_at(tokens, *i, "{") {
+        return methods;
+    }
+    *i += 1; // consume the opening `{`
+    let mut depth = 1i32;
+
+    while depth > 0 && *i < tokens.len() {
+        if punct_at(tokens, *i, "{") {
+            depth += 1;
+            *i += 1;
+        } else if punct_at(tokens, *i, "}") {
+            depth -= 1;
+            *i += 1;
+        } else if depth == 1 && ident_at(tokens, *i) == Some("fn") {
+            methods.push(parse_method_sig(tokens, i));
+        } else {
+            *i += 1;
+        }
+    }
+
+    methods
+}
+
+/// Parses a single method header (`tokens[*i]` must be `fn`), skipping its
+/// generics/parameters/return type, and records whether it has a body.
+fn parse_method_sig(tokens: &[Token], i: &mut usize) -> MethodSig {
+    *i += 1; // consume `fn`
+    let name = ident_at(tokens, *i).unwrap_or_default().to_string();
+    *i += 1;
+
+    let mut generics = maybe_parse_generics(tokens, i);
+
+    if punct_at(tokens, *i, "(") {
+        skip_balanced(tokens, i, "(", ")");
+    }
+
+    if punct_at(tokens, *i, "->") {
+        *i += 1;
+        while *i < tokens.len()
+            && !punct_at(tokens, *i, "{")
+            && !punct_at(tokens, *i, ";")
+            && ident_at(tokens, *i) != Some("where")
+        {
+            *i += 1;
+        }
+    }
+
+    maybe_merge_where(tokens, i, &mut generics);
+
+    let has_body = punct_at(tokens, *i, "{");
+    skip_item_body(tokens, i);
+
+    MethodSig { name, has_body }
+}
+
+/// Renders a token slice back into readable source, with just enough
+/// spacing heuristics to look like a type/bound expression.
+fn render_tokens(tokens: &[Token]) -> String {
+    let mut out = String::new();
+    for (idx, tok) in tokens.iter().enumerate() {
+        if idx > 0 {
+            let prev = &tokens[idx - 1];
+            let needs_space = !matches!(tok.text.as_str(), "::" | "," | "(" | ")" | "<" | ">")
+                && !matches!(prev.text.as_str(), "::" | "(" | "<");
+            if needs_space {
+                out.push(' ');
+            }
+        }
+        out.push_str(&tok.text);
+    }
+    out.trim().to_string()
+}
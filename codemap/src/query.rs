@@ -0,0 +1,240 @@
+//! A small query language for filtering extracted symbols, e.g.
+//! `kind:fn and async`, `name:~User* and visibility:pub`, or
+//! `kind:struct and has-field:id`.
+//!
+//! Grammar (looser than Rust's own operator precedence, but familiar):
+//!
+//! ```text
+//! expr       := or_expr
+//! or_expr    := and_expr ("or" and_expr)*
+//! and_expr   := unary ("and" unary)*
+//! unary      := "not" unary | primary
+//! primary    := "(" expr ")" | predicate
+//! predicate  := "async" | "unsafe" | "const" | field ":" value
+//! field      := "kind" | "name" | "visibility" | "impls" | "has-field"
+//! ```
+//!
+//! A `name`/`has-field` value prefixed with `~` is matched as a glob
+//! (`*` = any run of characters); otherwise it must match exactly.
+
+use crate::graph::TraitGraph;
+use crate::model::{Symbol, SymbolKind, Visibility};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Matcher {
+    Exact(String),
+    Glob(String),
+}
+
+impl Matcher {
+    fn parse(value: &str) -> Matcher {
+        match value.strip_prefix('~') {
+            Some(pattern) => Matcher::Glob(pattern.to_string()),
+            None => Matcher::Exact(value.to_string()),
+        }
+    }
+
+    fn matches(&self, text: &str) -> bool {
+        match self {
+            Matcher::Exact(s) => s == text,
+            Matcher::Glob(pattern) => match_glob(pattern, text),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate {
+    Kind(SymbolKind),
+    Name(Matcher),
+    Visibility(Visibility),
+    IsAsync,
+    IsUnsafe,
+    IsConst,
+    Implements(String),
+    HasField(Matcher),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryNode {
+    And(Box<QueryNode>, Box<QueryNode>),
+    Or(Box<QueryNode>, Box<QueryNode>),
+    Not(Box<QueryNode>),
+    Predicate(Predicate),
+}
+
+/// Parses a query string into a [`QueryNode`] AST.
+pub fn parse_query(input: &str) -> Result<QueryNode, String> {
+    let tokens = lex_query(input);
+    if tokens.is_empty() {
+        return Err("empty query".to_string());
+    }
+    let mut pos = 0;
+    let node = parse_or(&tokens, &mut pos)?;
+    if pos != tokens.len() {
+        return Err(format!("unexpected trailing input near `{}`", tokens[pos]));
+    }
+    Ok(node)
+}
+
+/// Evaluates a parsed query against a symbol set, returning the matches
+/// in their original order. `graph` supplies the `impls:` relationship.
+pub fn evaluate<'a>(node: &QueryNode, symbols: &'a [Symbol], graph: &TraitGraph) -> Vec<&'a Symbol> {
+    symbols.iter().filter(|sym| eval_node(node, sym, graph)).collect()
+}
+
+fn eval_node(node: &QueryNode, sym: &Symbol, graph: &TraitGraph) -> bool {
+    match node {
+        QueryNode::And(a, b) => eval_node(a, sym, graph) && eval_node(b, sym, graph),
+        QueryNode::Or(a, b) => eval_node(a, sym, graph) || eval_node(b, sym, graph),
+        QueryNode::Not(a) => !eval_node(a, sym, graph),
+        QueryNode::Predicate(p) => eval_predicate(p, sym, graph),
+    }
+}
+
+fn eval_predicate(predicate: &Predicate, sym: &Symbol, graph: &TraitGraph) -> bool {
+    match predicate {
+        Predicate::Kind(k) => sym.kind == *k,
+        Predicate::Name(m) => m.matches(&sym.name) || m.matches(last_segment(&sym.name)),
+        Predicate::Visibility(v) => sym.visibility == *v,
+        Predicate::IsAsync => sym.is_async,
+        Predicate::IsUnsafe => sym.is_unsafe,
+        Predicate::IsConst => sym.is_const,
+        Predicate::Implements(trait_name) => graph
+            .implements
+            .get(last_segment(&sym.name))
+            .is_some_and(|traits| traits.iter().any(|t| t == trait_name)),
+        Predicate::HasField(m) => sym.fields.iter().any(|f| m.matches(f)),
+    }
+}
+
+fn last_segment(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+/// A minimal `*`-glob matcher (no `?`, no character classes).
+fn match_glob(pattern: &str, text: &str) -> bool {
+    fn go(p: &[u8], t: &[u8]) -> bool {
+        match p.first() {
+            None => t.is_empty(),
+            Some(b'*') => go(&p[1..], t) || (!t.is_empty() && go(p, &t[1..])),
+            Some(pc) => t.first() == Some(pc) && go(&p[1..], &t[1..]),
+        }
+    }
+    go(pattern.as_bytes(), text.as_bytes())
+}
+
+/// Splits a query string into tokens: `(`, `)`, and whitespace-delimited
+/// words (`kind:fn`, `and`, `not`, `name:~User*`, ...).
+fn lex_query(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    for c in input.chars() {
+        match c {
+            '(' | ')' => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                tokens.push(c.to_string());
+            }
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+fn parse_or(tokens: &[String], pos: &mut usize) -> Result<QueryNode, String> {
+    let mut node = parse_and(tokens, pos)?;
+    while tokens.get(*pos).map(String::as_str) == Some("or") {
+        *pos += 1;
+        let rhs = parse_and(tokens, pos)?;
+        node = QueryNode::Or(Box::new(node), Box::new(rhs));
+    }
+    Ok(node)
+}
+
+fn parse_and(tokens: &[String], pos: &mut usize) -> Result<QueryNode, String> {
+    let mut node = parse_unary(tokens, pos)?;
+    while tokens.get(*pos).map(String::as_str) == Some("and") {
+        *pos += 1;
+        let rhs = parse_unary(tokens, pos)?;
+        node = QueryNode::And(Box::new(node), Box::new(rhs));
+    }
+    Ok(node)
+}
+
+fn parse_unary(tokens: &[String], pos: &mut usize) -> Result<QueryNode, String> {
+    if tokens.get(*pos).map(String::as_str) == Some("not") {
+        *pos += 1;
+        return Ok(QueryNode::Not(Box::new(parse_unary(tokens, pos)?)));
+    }
+    parse_primary(tokens, pos)
+}
+
+fn parse_primary(tokens: &[String], pos: &mut usize) -> Result<QueryNode, String> {
+    match tokens.get(*pos).map(String::as_str) {
+        Some("(") => {
+            *pos += 1;
+            let node = parse_or(tokens, pos)?;
+            if tokens.get(*pos).map(String::as_str) != Some(")") {
+                return Err("expected closing `)`".to_string());
+            }
+            *pos += 1;
+            Ok(node)
+        }
+        Some(word) => {
+            let predicate = parse_predicate(word)?;
+            *pos += 1;
+            Ok(QueryNode::Predicate(predicate))
+        }
+        None => Err("expected a predicate".to_string()),
+    }
+}
+
+fn parse_predicate(word: &str) -> Result<Predicate, String> {
+    match word {
+        "async" => return Ok(Predicate::IsAsync),
+        "unsafe" => return Ok(Predicate::IsUnsafe),
+        "const" => return Ok(Predicate::IsConst),
+        _ => {}
+    }
+    let (field, value) = word
+        .split_once(':')
+        .ok_or_else(|| format!("expected `field:value`, `async`, `unsafe`, or `const`, got `{word}`"))?;
+
+    match field {
+        "kind" => parse_kind(value).map(Predicate::Kind),
+        "name" => Ok(Predicate::Name(Matcher::parse(value))),
+        "visibility" => parse_visibility(value).map(Predicate::Visibility),
+        "impls" => Ok(Predicate::Implements(value.to_string())),
+        "has-field" => Ok(Predicate::HasField(Matcher::parse(value))),
+        other => Err(format!("unknown query field `{other}`")),
+    }
+}
+
+fn parse_kind(value: &str) -> Result<SymbolKind, String> {
+    match value {
+        "struct" => Ok(SymbolKind::Struct),
+        "enum" => Ok(SymbolKind::Enum),
+        "trait" => Ok(SymbolKind::Trait),
+        "impl" => Ok(SymbolKind::Impl),
+        "fn" => Ok(SymbolKind::Function),
+        other => Err(format!("unknown kind `{other}`")),
+    }
+}
+
+fn parse_visibility(value: &str) -> Result<Visibility, String> {
+    match value {
+        "pub" => Ok(Visibility::Public),
+        "pub(crate)" | "crate" => Ok(Visibility::PubCrate),
+        "private" => Ok(Visibility::Private),
+        other => Err(format!("unknown visibility `{other}`")),
+    }
+}
@@ -61,10 +61,80 @@ pub async fn process_data(data: Vec<u8>) -> Result<Vec<u8>, Box<dyn Error>> {
     Ok(data)
 }
 
+/// Reads a raw byte out of `ptr`; the caller must ensure it's valid.
+pub unsafe fn read_raw(ptr: *const u8) -> u8 {
+    *ptr
+}
+
+/// Squares a number at compile time.
+pub const fn square(n: u32) -> u32 {
+    n * n
+}
+
 /// Submodule for utilities.
 pub mod utils {
     /// Formats a number.
     pub fn format_number(n: u32) -> String {
         n.to_string()
     }
+
+    /// Private helper, not part of the public API surface.
+    fn pad(n: u32) -> String {
+        format!("{n:>4}")
+    }
+
+    /// A private submodule: even its `pub` items can't be reached from
+    /// outside the crate, since `internal` itself isn't `pub`.
+    mod internal {
+        pub fn scratch() -> u32 {
+            0
+        }
+    }
+}
+
+/// A generic point in 2D space.
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Operations available on any `Point<T>`.
+impl<T> Point<T> {
+    /// Creates a new point.
+    pub fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+/// A specialized implementation for `f32` points.
+impl Point<f32> {
+    /// Computes the distance from the origin.
+    pub fn magnitude(&self) -> f32 {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+}
+
+/// Prints a summary, requiring that `T` can be cloned and is `'static`.
+pub fn describe<T>(item: &T) -> String
+where
+    T: Clone + 'static,
+{
+    let _ = item.clone();
+    "item".to_string()
+}
+
+/// A trait with a default method, to exercise override-vs-inherit tracking.
+pub trait Describable {
+    fn label(&self) -> String;
+
+    /// Falls back to the bare label unless an implementor overrides it.
+    fn describe(&self) -> String {
+        self.label()
+    }
+}
+
+impl Describable for User {
+    fn label(&self) -> String {
+        self.name.clone()
+    }
 }
@@ -0,0 +1,35 @@
+use codemap::{parse_source, SymbolKind};
+
+const FIXTURE: &str = include_str!("fixtures/sample_module.rs");
+
+#[test]
+fn extracts_plain_and_generic_items_from_fixture() {
+    let symbols = parse_source(FIXTURE);
+
+    let user = symbols.iter().find(|s| s.name == "User").unwrap();
+    assert_eq!(user.kind, SymbolKind::Struct);
+    assert_eq!(user.display_name(), "User");
+
+    let point = symbols
+        .iter()
+        .find(|s| s.kind == SymbolKind::Struct && s.name == "Point")
+        .unwrap();
+    assert_eq!(point.display_name(), "Point<T>");
+
+    let impls: Vec<_> = symbols
+        .iter()
+        .filter(|s| s.kind == SymbolKind::Impl && s.name.starts_with("Point"))
+        .collect();
+    assert!(impls.iter().any(|s| s.display_name() == "Point<T>"));
+    assert!(impls.iter().any(|s| s.display_name() == "Point<f32>"));
+}
+
+#[test]
+fn merges_where_clause_bounds_into_the_type_param() {
+    let symbols = parse_source(FIXTURE);
+    let describe = symbols
+        .iter()
+        .find(|s| s.kind == SymbolKind::Function && s.name == "describe")
+        .unwrap();
+    assert_eq!(describe.display_name(), "describe<T: Clone + 'static>");
+}
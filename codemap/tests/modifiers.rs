@@ -0,0 +1,56 @@
+use codemap::{build_trait_graph, evaluate, parse_query, parse_source};
+
+const FIXTURE: &str = include_str!("fixtures/sample_module.rs");
+
+#[test]
+fn captures_async_unsafe_and_const_modifiers() {
+    let symbols = parse_source(FIXTURE);
+
+    let process_data = symbols.iter().find(|s| s.name == "process_data").unwrap();
+    assert!(process_data.is_async);
+    assert!(!process_data.is_unsafe);
+    assert!(!process_data.is_const);
+
+    let read_raw = symbols.iter().find(|s| s.name == "read_raw").unwrap();
+    assert!(read_raw.is_unsafe);
+    assert!(!read_raw.is_async);
+
+    let square = symbols.iter().find(|s| s.name == "square").unwrap();
+    assert!(square.is_const);
+    assert!(!square.is_async);
+
+    let greet = symbols.iter().find(|s| s.name == "greet").unwrap();
+    assert!(!greet.is_async && !greet.is_unsafe && !greet.is_const);
+}
+
+#[test]
+fn renders_modifiers_in_signature_order() {
+    let symbols = parse_source(FIXTURE);
+    let output = codemap::output::render_outline(&symbols);
+    assert!(output.contains("async fn process_data"));
+    assert!(output.contains("unsafe fn read_raw"));
+    assert!(output.contains("const fn square"));
+    assert!(output.contains("fn greet"));
+    assert!(!output.contains("fn async process_data"));
+}
+
+#[test]
+fn filters_by_unsafe_and_const_predicates() {
+    let symbols = parse_source(FIXTURE);
+    let graph = build_trait_graph(&symbols);
+
+    let node = parse_query("unsafe").unwrap();
+    let matches = evaluate(&node, &symbols, &graph);
+    assert!(matches.iter().any(|s| s.name == "read_raw"));
+    assert!(!matches.iter().any(|s| s.name == "square"));
+
+    let node = parse_query("const").unwrap();
+    let matches = evaluate(&node, &symbols, &graph);
+    assert!(matches.iter().any(|s| s.name == "square"));
+    assert!(!matches.iter().any(|s| s.name == "read_raw"));
+
+    let node = parse_query("kind:fn and not (async or unsafe or const)").unwrap();
+    let matches = evaluate(&node, &symbols, &graph);
+    assert!(matches.iter().any(|s| s.name == "greet"));
+    assert!(!matches.iter().any(|s| s.name == "read_raw"));
+}
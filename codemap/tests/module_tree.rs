@@ -0,0 +1,52 @@
+use codemap::{parse_module, parse_source, Visibility};
+
+const FIXTURE: &str = include_str!("fixtures/sample_module.rs");
+
+#[test]
+fn qualifies_names_with_their_enclosing_module_path() {
+    let symbols = parse_source(FIXTURE);
+    assert!(symbols.iter().any(|s| s.name == "utils::format_number"));
+    assert!(symbols.iter().any(|s| s.name == "utils::internal::scratch"));
+    // Crate-root items stay bare.
+    assert!(symbols.iter().any(|s| s.name == "User"));
+}
+
+#[test]
+fn resolves_visibility_per_item() {
+    let symbols = parse_source(FIXTURE);
+    let pad = symbols.iter().find(|s| s.name == "utils::pad").unwrap();
+    assert_eq!(pad.visibility, Visibility::Private);
+
+    let format_number = symbols.iter().find(|s| s.name == "utils::format_number").unwrap();
+    assert_eq!(format_number.visibility, Visibility::Public);
+}
+
+#[test]
+fn external_visibility_requires_every_enclosing_module_to_be_pub() {
+    let symbols = parse_source(FIXTURE);
+
+    let format_number = symbols.iter().find(|s| s.name == "utils::format_number").unwrap();
+    assert!(format_number.externally_visible, "pub fn in a pub mod is part of the public API");
+
+    let pad = symbols.iter().find(|s| s.name == "utils::pad").unwrap();
+    assert!(!pad.externally_visible, "a private fn can never be externally visible");
+
+    let scratch = symbols.iter().find(|s| s.name == "utils::internal::scratch").unwrap();
+    assert!(
+        !scratch.externally_visible,
+        "pub fn inside a private mod is not reachable from outside the crate"
+    );
+}
+
+#[test]
+fn builds_a_nested_module_tree() {
+    let root = parse_module(FIXTURE);
+    let utils = root.modules.iter().find(|m| m.name == "utils").unwrap();
+    assert_eq!(utils.path, "utils");
+    assert_eq!(utils.visibility, Visibility::Public);
+    assert!(utils.symbols.iter().any(|s| s.name == "utils::format_number"));
+
+    let internal = utils.modules.iter().find(|m| m.name == "internal").unwrap();
+    assert_eq!(internal.path, "utils::internal");
+    assert_eq!(internal.visibility, Visibility::Private);
+}
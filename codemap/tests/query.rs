@@ -0,0 +1,87 @@
+use codemap::{build_trait_graph, evaluate, parse_query, parse_source};
+
+const FIXTURE: &str = include_str!("fixtures/sample_module.rs");
+
+#[test]
+fn filters_by_kind() {
+    let symbols = parse_source(FIXTURE);
+    let graph = build_trait_graph(&symbols);
+    let node = parse_query("kind:trait").unwrap();
+    let matches = evaluate(&node, &symbols, &graph);
+    assert!(matches.iter().any(|s| s.name == "UserService"));
+    assert!(matches.iter().any(|s| s.name == "Describable"));
+    assert!(!matches.iter().any(|s| s.name == "User"));
+}
+
+#[test]
+fn filters_by_name_glob() {
+    let symbols = parse_source(FIXTURE);
+    let graph = build_trait_graph(&symbols);
+    let node = parse_query("name:~User*").unwrap();
+    let matches = evaluate(&node, &symbols, &graph);
+    assert!(matches.iter().any(|s| s.name == "UserService"));
+    assert!(matches.iter().any(|s| s.name == "UserStatus"));
+    assert!(!matches.iter().any(|s| s.name == "DefaultService"));
+}
+
+#[test]
+fn filters_by_visibility_and_async() {
+    let symbols = parse_source(FIXTURE);
+    let graph = build_trait_graph(&symbols);
+
+    let node = parse_query("kind:fn and async").unwrap();
+    let matches = evaluate(&node, &symbols, &graph);
+    assert!(matches.iter().any(|s| s.name == "process_data"));
+    assert!(!matches.iter().any(|s| s.name == "greet"));
+
+    let node = parse_query("visibility:pub and kind:struct").unwrap();
+    let matches = evaluate(&node, &symbols, &graph);
+    assert!(matches.iter().any(|s| s.name == "User"));
+}
+
+#[test]
+fn filters_by_trait_implementation() {
+    let symbols = parse_source(FIXTURE);
+    let graph = build_trait_graph(&symbols);
+    let node = parse_query("impls:UserService").unwrap();
+    let matches = evaluate(&node, &symbols, &graph);
+    assert!(matches.iter().any(|s| s.name == "DefaultService"));
+    assert!(!matches.iter().any(|s| s.name == "User"));
+}
+
+#[test]
+fn filters_by_field_presence() {
+    let symbols = parse_source(FIXTURE);
+    let graph = build_trait_graph(&symbols);
+    let node = parse_query("kind:struct and has-field:id").unwrap();
+    let matches = evaluate(&node, &symbols, &graph);
+    assert!(matches.iter().any(|s| s.name == "User"));
+    assert!(!matches.iter().any(|s| s.name == "Point"));
+}
+
+#[test]
+fn combines_predicates_with_or_and_not_and_parens() {
+    let symbols = parse_source(FIXTURE);
+    let graph = build_trait_graph(&symbols);
+
+    let node = parse_query("kind:trait or kind:enum").unwrap();
+    let matches = evaluate(&node, &symbols, &graph);
+    assert!(matches.iter().any(|s| s.name == "UserService"));
+    assert!(matches.iter().any(|s| s.name == "UserStatus"));
+
+    let node = parse_query("kind:fn and not async").unwrap();
+    let matches = evaluate(&node, &symbols, &graph);
+    assert!(matches.iter().any(|s| s.name == "greet"));
+    assert!(!matches.iter().any(|s| s.name == "process_data"));
+
+    let node = parse_query("(kind:struct or kind:enum) and visibility:pub").unwrap();
+    let matches = evaluate(&node, &symbols, &graph);
+    assert!(matches.iter().any(|s| s.name == "UserStatus"));
+}
+
+#[test]
+fn rejects_malformed_queries() {
+    assert!(parse_query("").is_err());
+    assert!(parse_query("kind:bogus").is_err());
+    assert!(parse_query("(kind:fn").is_err());
+}
@@ -0,0 +1,44 @@
+use codemap::{build_trait_graph, parse_source};
+
+const FIXTURE: &str = include_str!("fixtures/sample_module.rs");
+
+#[test]
+fn links_default_service_to_user_service() {
+    let symbols = parse_source(FIXTURE);
+    let graph = build_trait_graph(&symbols);
+
+    let implementors = graph.implementors.get("UserService").expect("UserService should have implementors");
+    assert_eq!(implementors.len(), 1);
+    let default_service = &implementors[0];
+    assert_eq!(default_service.self_type, "DefaultService");
+    assert!(default_service.resolved);
+    assert!(default_service.overridden.contains(&"get_user".to_string()));
+    assert!(default_service.overridden.contains(&"create_user".to_string()));
+    assert!(default_service.inherited.is_empty());
+
+    assert_eq!(
+        graph.implements.get("DefaultService"),
+        Some(&vec!["UserService".to_string()])
+    );
+}
+
+#[test]
+fn tracks_inherited_default_methods() {
+    let symbols = parse_source(FIXTURE);
+    let graph = build_trait_graph(&symbols);
+
+    let implementors = graph.implementors.get("Describable").expect("Describable should have implementors");
+    let user_impl = implementors.iter().find(|i| i.self_type == "User").unwrap();
+    assert_eq!(user_impl.overridden, vec!["label".to_string()]);
+    assert_eq!(user_impl.inherited, vec!["describe".to_string()]);
+}
+
+#[test]
+fn inherent_impls_are_kept_separate_from_trait_impls() {
+    let symbols = parse_source(FIXTURE);
+    let graph = build_trait_graph(&symbols);
+
+    let user_impls = graph.inherent_impls.get("User").expect("User should have an inherent impl");
+    assert_eq!(user_impls, &vec!["User".to_string()]);
+    assert!(!graph.inherent_impls.contains_key("DefaultService"));
+}